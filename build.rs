@@ -0,0 +1,27 @@
+//! Assembles the hand-written outline syscall stubs when the
+//! `outline-syscall` feature is enabled, so that `arch::mips64::outline`'s
+//! `extern "C"` declarations resolve to real symbols.
+//!
+//! This assumes `Cargo.toml` declares:
+//!
+//! ```toml
+//! [features]
+//! outline-syscall = []
+//!
+//! [build-dependencies]
+//! cc = "1"
+//! ```
+fn main() {
+    println!("cargo:rerun-if-changed=src/arch/mips64/syscall.s");
+
+    if std::env::var_os("CARGO_FEATURE_OUTLINE_SYSCALL").is_none() {
+        return;
+    }
+
+    let target_arch = std::env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+    if target_arch == "mips64" {
+        cc::Build::new()
+            .file("src/arch/mips64/syscall.s")
+            .compile("syscall_outline");
+    }
+}
@@ -0,0 +1,635 @@
+// MIPS32r6 has the following registers:
+//
+// | Symbolic Name | Number          | Usage                          |
+// | ============= | =============== | ============================== |
+// | zero          | 0               | Constant 0.                    |
+// | at            | 1               | Reserved for the assembler.    |
+// | v0 - v1       | 2 - 3           | Result Registers.              |
+// | a0 - a3       | 4 - 7           | Argument Registers 1 ·· · 4.   |
+// | t0 - t9       | 8 - 15, 24 - 25 | Temporary Registers 0 · · · 9. |
+// | s0 - s7       | 16 - 23         | Saved Registers 0 ·· · 7.      |
+// | k0 - k1       | 26 - 27         | Kernel Registers 0 ·· · 1.     |
+// | gp            | 28              | Global Data Pointer.           |
+// | sp            | 29              | Stack Pointer.                 |
+// | fp            | 30              | Frame Pointer.                 |
+// | ra            | 31              | Return Address.                |
+//
+// The following registers are used for args 1-4:
+//
+// arg1: %a0 ($4)
+// arg2: %a1 ($5)
+// arg3: %a2 ($6)
+// arg4: %a3 ($7)
+//
+// %v0 is the syscall number.
+// %v0 is the return value.
+// %a3 is a boolean indicating that an error occurred.
+//
+// All temporary registers are clobbered (8-15, 24-25).
+//
+// NOTE: Release 6 of the MIPS32 architecture re-encodes several
+// instructions and removes the requirement that `syscall` execute inside a
+// branch-delay slot, but it keeps the o32 calling convention: args 5 and 6
+// are still passed on the stack, at offsets 16 and 20 from the stack
+// pointer after a 32-byte frame is reserved below it. This module is selected by `target_arch =
+// "mips32r6"` (confirmed via `rustc --print cfg --target
+// mipsisa32r6-unknown-linux-gnu`, which reports `target_arch="mips32r6"`,
+// not `"mipsisa32r6"`) so that the crate builds and runs correctly on r6
+// targets instead of silently falling through to (or failing to build on)
+// the classic MIPS module.
+//
+// This mirrors `arch::mips`, including its `_readonly` variants for every
+// arity (with the same caveat as `arch::mips` that `syscall5_readonly` and
+// `syscall6_readonly` cannot actually use `options(readonly)`, since they
+// still spill to the stack).
+#![cfg(target_arch = "mips32r6")]
+
+use core::arch::asm;
+
+use super::syscalls::Sysno;
+
+/// Issues a raw system call with 0 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall0(n: Sysno) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        lateout("$7") err,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags, readonly)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
+/// Issues a raw system call with 1 argument.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall1(n: Sysno, arg1: usize) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        lateout("$7") err,
+        in("$4") arg1,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
+/// Issues a raw system call with 1 argument, asserting that the call does
+/// not write to memory.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety. The caller must also ensure that the
+/// system call does not write to memory in any way.
+#[inline]
+pub unsafe fn syscall1_readonly(n: Sysno, arg1: usize) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        lateout("$7") err,
+        in("$4") arg1,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags, readonly)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
+/// Issues a raw system call with 2 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall2(n: Sysno, arg1: usize, arg2: usize) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        lateout("$7") err,
+        in("$4") arg1,
+        in("$5") arg2,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
+/// Issues a raw system call with 2 arguments, asserting that the call does
+/// not write to memory.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety. The caller must also ensure that the
+/// system call does not write to memory in any way.
+#[inline]
+pub unsafe fn syscall2_readonly(n: Sysno, arg1: usize, arg2: usize) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        lateout("$7") err,
+        in("$4") arg1,
+        in("$5") arg2,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags, readonly)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
+/// Issues a raw system call with 3 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall3(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        lateout("$7") err,
+        in("$4") arg1,
+        in("$5") arg2,
+        in("$6") arg3,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
+/// Issues a raw system call with 3 arguments, asserting that the call does
+/// not write to memory.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety. The caller must also ensure that the
+/// system call does not write to memory in any way.
+#[inline]
+pub unsafe fn syscall3_readonly(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        lateout("$7") err,
+        in("$4") arg1,
+        in("$5") arg2,
+        in("$6") arg3,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags, readonly)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
+/// Issues a raw system call with 4 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall4(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        in("$4") arg1,
+        in("$5") arg2,
+        in("$6") arg3,
+        // $7 is now used for both input and output.
+        inlateout("$7") arg4 => err,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
+/// Issues a raw system call with 4 arguments, asserting that the call does
+/// not write to memory.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety. The caller must also ensure that the
+/// system call does not write to memory in any way.
+#[inline]
+pub unsafe fn syscall4_readonly(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        in("$4") arg1,
+        in("$5") arg2,
+        in("$6") arg3,
+        // $7 is now used for both input and output.
+        inlateout("$7") arg4 => err,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags, readonly)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
+/// Issues a raw system call with 5 arguments.
+///
+/// The 5th argument is passed on the stack, as mandated by the o32 calling
+/// convention: there are only 4 argument registers ($4-$7), so beyond that
+/// arguments spill to the caller's stack frame starting at offset 16.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall5(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "addiu $sp, $sp, -32",
+        "sw {arg5}, 16($sp)",
+        "syscall",
+        "addiu $sp, $sp, 32",
+        arg5 = in(reg) arg5,
+        inlateout("$2") n as usize => ret,
+        in("$4") arg1,
+        in("$5") arg2,
+        in("$6") arg3,
+        // $7 is now used for both input and output.
+        inlateout("$7") arg4 => err,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(preserves_flags)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
+/// Issues a raw system call with 5 arguments, nominally asserting that the
+/// call does not write to memory.
+///
+/// Unlike `syscall1_readonly`..`syscall4_readonly`, this cannot use
+/// `options(readonly)`: the 5th argument is spilled to the stack with an
+/// explicit `sw`, which is a memory write, so claiming "readonly" here
+/// would be unsound. This variant exists only so every arity has a
+/// `_readonly`-suffixed entry point; it does not get the optimization.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety. The caller must also ensure that the
+/// system call does not write to memory in any way other than the stack
+/// spill this function itself performs.
+#[inline]
+pub unsafe fn syscall5_readonly(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "addiu $sp, $sp, -32",
+        "sw {arg5}, 16($sp)",
+        "syscall",
+        "addiu $sp, $sp, 32",
+        arg5 = in(reg) arg5,
+        inlateout("$2") n as usize => ret,
+        in("$4") arg1,
+        in("$5") arg2,
+        in("$6") arg3,
+        // $7 is now used for both input and output.
+        inlateout("$7") arg4 => err,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(preserves_flags)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
+/// Issues a raw system call with 6 arguments.
+///
+/// The 5th and 6th arguments are passed on the stack, as mandated by the o32
+/// calling convention: there are only 4 argument registers ($4-$7), so
+/// beyond that arguments spill to the caller's stack frame starting at
+/// offset 16.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall6(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+    arg6: usize,
+) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "addiu $sp, $sp, -32",
+        "sw {arg5}, 16($sp)",
+        "sw {arg6}, 20($sp)",
+        "syscall",
+        "addiu $sp, $sp, 32",
+        arg5 = in(reg) arg5,
+        arg6 = in(reg) arg6,
+        inlateout("$2") n as usize => ret,
+        in("$4") arg1,
+        in("$5") arg2,
+        in("$6") arg3,
+        // $7 is now used for both input and output.
+        inlateout("$7") arg4 => err,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(preserves_flags)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
+/// Issues a raw system call with 6 arguments, nominally asserting that the
+/// call does not write to memory.
+///
+/// Unlike `syscall1_readonly`..`syscall4_readonly`, this cannot use
+/// `options(readonly)`: the 5th and 6th arguments are spilled to the stack
+/// with explicit `sw` instructions, which are memory writes, so claiming
+/// "readonly" here would be unsound. This variant exists only so every
+/// arity has a `_readonly`-suffixed entry point; it does not get the
+/// optimization.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety. The caller must also ensure that the
+/// system call does not write to memory in any way other than the stack
+/// spill this function itself performs.
+#[inline]
+pub unsafe fn syscall6_readonly(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+    arg6: usize,
+) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "addiu $sp, $sp, -32",
+        "sw {arg5}, 16($sp)",
+        "sw {arg6}, 20($sp)",
+        "syscall",
+        "addiu $sp, $sp, 32",
+        arg5 = in(reg) arg5,
+        arg6 = in(reg) arg6,
+        inlateout("$2") n as usize => ret,
+        in("$4") arg1,
+        in("$5") arg2,
+        in("$6") arg3,
+        // $7 is now used for both input and output.
+        inlateout("$7") arg4 => err,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(preserves_flags)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
@@ -0,0 +1,146 @@
+//! Outline (out-of-line) syscall entry points for MIPS64.
+//!
+//! These call hand-written stubs in `syscall.s`, assembled and linked in by
+//! `build.rs` (see the crate root) when the `outline-syscall` feature is
+//! enabled. Unlike the inline `asm!` blocks in [`super::syscall`], which
+//! clobber all ten temporary registers at every call site and can inhibit
+//! inlining of the surrounding function, these are real functions behind a
+//! stable call boundary: smaller code size, but an extra call/return per
+//! syscall.
+#![cfg(feature = "outline-syscall")]
+
+use super::syscalls::Sysno;
+
+extern "C" {
+    fn __syscall0(n: usize) -> usize;
+    fn __syscall1(n: usize, arg1: usize) -> usize;
+    fn __syscall2(n: usize, arg1: usize, arg2: usize) -> usize;
+    fn __syscall3(n: usize, arg1: usize, arg2: usize, arg3: usize) -> usize;
+    fn __syscall4(
+        n: usize,
+        arg1: usize,
+        arg2: usize,
+        arg3: usize,
+        arg4: usize,
+    ) -> usize;
+    fn __syscall5(
+        n: usize,
+        arg1: usize,
+        arg2: usize,
+        arg3: usize,
+        arg4: usize,
+        arg5: usize,
+    ) -> usize;
+    fn __syscall6(
+        n: usize,
+        arg1: usize,
+        arg2: usize,
+        arg3: usize,
+        arg4: usize,
+        arg5: usize,
+        arg6: usize,
+    ) -> usize;
+}
+
+/// Issues a raw system call with 0 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall0(n: Sysno) -> usize {
+    __syscall0(n as usize)
+}
+
+/// Issues a raw system call with 1 argument.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall1(n: Sysno, arg1: usize) -> usize {
+    __syscall1(n as usize, arg1)
+}
+
+/// Issues a raw system call with 2 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall2(n: Sysno, arg1: usize, arg2: usize) -> usize {
+    __syscall2(n as usize, arg1, arg2)
+}
+
+/// Issues a raw system call with 3 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall3(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    __syscall3(n as usize, arg1, arg2, arg3)
+}
+
+/// Issues a raw system call with 4 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall4(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    __syscall4(n as usize, arg1, arg2, arg3, arg4)
+}
+
+/// Issues a raw system call with 5 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall5(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    __syscall5(n as usize, arg1, arg2, arg3, arg4, arg5)
+}
+
+/// Issues a raw system call with 6 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall6(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+    arg6: usize,
+) -> usize {
+    __syscall6(n as usize, arg1, arg2, arg3, arg4, arg5, arg6)
+}
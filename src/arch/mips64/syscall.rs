@@ -32,6 +32,14 @@
 // NOTE: The main difference between MIPS and MIPS64 is that MIPS64 doesn't use
 // the stack to pass in args 5-6. Instead, it uses the temporary registers t0
 // and t1, which still get clobbered.
+//
+// This module implements the n64 ABI used on `target_arch = "mips64"`. The
+// o32 ABI, which spills args 5-6 to the stack, lives in `arch::mips` and is
+// used on the 32-bit `target_arch = "mips"`.
+//
+// When the `outline-syscall` feature is enabled, callers get the
+// out-of-line stubs in `outline` instead of the inline `asm!` functions
+// below.
 use core::arch::asm;
 
 use super::syscalls::Sysno;
@@ -105,6 +113,43 @@ pub unsafe fn syscall1(n: Sysno, arg1: usize) -> usize {
     }
 }
 
+/// Issues a raw system call with 1 argument, asserting that the call does
+/// not write to memory.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety. The caller must also ensure that the
+/// system call does not write to memory in any way.
+#[inline]
+pub unsafe fn syscall1_readonly(n: Sysno, arg1: usize) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        lateout("$7") err,
+        in("$4") arg1,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags, readonly)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
 /// Issues a raw system call with 2 arguments.
 ///
 /// # Safety
@@ -141,6 +186,44 @@ pub unsafe fn syscall2(n: Sysno, arg1: usize, arg2: usize) -> usize {
     }
 }
 
+/// Issues a raw system call with 2 arguments, asserting that the call does
+/// not write to memory.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety. The caller must also ensure that the
+/// system call does not write to memory in any way.
+#[inline]
+pub unsafe fn syscall2_readonly(n: Sysno, arg1: usize, arg2: usize) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        lateout("$7") err,
+        in("$4") arg1,
+        in("$5") arg2,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags, readonly)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
 /// Issues a raw system call with 3 arguments.
 ///
 /// # Safety
@@ -183,6 +266,50 @@ pub unsafe fn syscall3(
     }
 }
 
+/// Issues a raw system call with 3 arguments, asserting that the call does
+/// not write to memory.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety. The caller must also ensure that the
+/// system call does not write to memory in any way.
+#[inline]
+pub unsafe fn syscall3_readonly(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        lateout("$7") err,
+        in("$4") arg1,
+        in("$5") arg2,
+        in("$6") arg3,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags, readonly)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
 /// Issues a raw system call with 4 arguments.
 ///
 /// # Safety
@@ -227,6 +354,52 @@ pub unsafe fn syscall4(
     }
 }
 
+/// Issues a raw system call with 4 arguments, asserting that the call does
+/// not write to memory.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety. The caller must also ensure that the
+/// system call does not write to memory in any way.
+#[inline]
+pub unsafe fn syscall4_readonly(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        in("$4") arg1,
+        in("$5") arg2,
+        in("$6") arg3,
+        // $7 is now used for both input and output.
+        inlateout("$7") arg4 => err,
+        // All temporary registers are always clobbered
+        lateout("$8") _,
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags, readonly)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
 /// Issues a raw system call with 5 arguments.
 ///
 /// # Safety
@@ -272,6 +445,53 @@ pub unsafe fn syscall5(
     }
 }
 
+/// Issues a raw system call with 5 arguments, asserting that the call does
+/// not write to memory.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety. The caller must also ensure that the
+/// system call does not write to memory in any way.
+#[inline]
+pub unsafe fn syscall5_readonly(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        in("$4") arg1,
+        in("$5") arg2,
+        in("$6") arg3,
+        // $7 is now used for both input and output.
+        inlateout("$7") arg4 => err,
+        inlateout("$8") arg5 => _,
+        // All temporary registers are always clobbered
+        lateout("$9") _,
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags, readonly)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}
+
 /// Issues a raw system call with 6 arguments.
 ///
 /// # Safety
@@ -317,3 +537,51 @@ pub unsafe fn syscall6(
         ret.wrapping_neg()
     }
 }
+
+/// Issues a raw system call with 6 arguments, asserting that the call does
+/// not write to memory.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety. The caller must also ensure that the
+/// system call does not write to memory in any way.
+#[inline]
+pub unsafe fn syscall6_readonly(
+    n: Sysno,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+    arg6: usize,
+) -> usize {
+    let mut err: usize;
+    let mut ret: usize;
+    asm!(
+        "syscall",
+        inlateout("$2") n as usize => ret,
+        in("$4") arg1,
+        in("$5") arg2,
+        in("$6") arg3,
+        // $7 is now used for both input and output.
+        inlateout("$7") arg4 => err,
+        inlateout("$8") arg5 => _,
+        inlateout("$9") arg6 => _,
+        // All temporary registers are always clobbered
+        lateout("$10") _,
+        lateout("$11") _,
+        lateout("$12") _,
+        lateout("$13") _,
+        lateout("$14") _,
+        lateout("$15") _,
+        lateout("$24") _,
+        lateout("$25") _,
+        options(nostack, preserves_flags, readonly)
+    );
+    if err == 0 {
+        ret
+    } else {
+        ret.wrapping_neg()
+    }
+}